@@ -8,7 +8,8 @@
 
 #include "Bomb.h"
 
-Bomb::Bomb() {
+Bomb::Bomb(int maxRadius, int energy)
+    : m_radius(0), m_maxRadius(maxRadius), m_energy(energy) {
 
 }
 
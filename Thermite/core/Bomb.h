@@ -19,7 +19,7 @@ class BuildingBlock;
 class Bomb {
 
 public:
-    Bomb();
+    Bomb(int maxRadius, int energy);
     ~Bomb();
     
     virtual const char* getName() = 0;
@@ -36,8 +36,8 @@ protected:
     int det(int x1, int y1, int x2, int y2, int x3, int y3); 
     b2Vec2 m_position;
     int m_radius;
-    static const int m_maxRadius = 50;
-    static const int m_energy = 1000;
+    int m_maxRadius;
+    int m_energy;
 };
 
 #endif
@@ -10,7 +10,7 @@
 
 using namespace cocos2d;
 
-LegoBomb::LegoBomb() {
+LegoBomb::LegoBomb() : Bomb(100, 1000) {
     
 }
 
@@ -22,8 +22,6 @@ public:
 
 private:
     b2Vec2 getEdgeBreakPoint(b2Body*, b2Vec2, int, int);
-    
-    static const int m_maxRadius = 100;
 };
 
 #endif